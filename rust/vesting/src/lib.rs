@@ -3,6 +3,7 @@
 use std::net::SocketAddr;
 
 use axum::{routing::post, Json, Router};
+use chrono::{DateTime, Datelike, Months, Utc};
 use serde::{Deserialize, Serialize};
 use thiserror::Error;
 
@@ -13,7 +14,20 @@ pub enum VestingSchedule {
     /// A cliff schedule vests 100% after the specified number of months.
     Cliff { cliff_months: u32 },
     /// A graded schedule vests linearly after a cliff across the remaining months.
-    Graded { cliff_months: u32, total_months: u32 },
+    Graded {
+        cliff_months: u32,
+        total_months: u32,
+    },
+    /// A periodic schedule vests in discrete steps every `period_months` after
+    /// the cliff, rather than continuously. When `total_months - cliff_months`
+    /// does not divide evenly by `period_months`, the first period absorbs the
+    /// remainder so the window still partitions exactly instead of leaving a
+    /// ragged final stub.
+    Periodic {
+        cliff_months: u32,
+        total_months: u32,
+        period_months: u32,
+    },
 }
 
 /// Input payload accepted by the HTTP endpoint.
@@ -25,6 +39,24 @@ pub struct VestingRequest {
     pub months_elapsed: u32,
     /// Schedule configuration.
     pub schedule: VestingSchedule,
+    /// Total amount expressed in the smallest indivisible unit (cents, wei, ...).
+    ///
+    /// When present, the handler additionally runs the integer-precision path and
+    /// reports `vested_units`/`remaining_units` on the response so callers never
+    /// have to round a floating-point amount themselves. Not supported together
+    /// with `grant_start`/`as_of` (see [`VestingError::TimestampUnitsUnsupported`]),
+    /// since the integer path only has whole-month precision and could disagree
+    /// with the timestamp-exact `vested_fraction`.
+    #[serde(default)]
+    pub total_units: Option<u128>,
+    /// Grant start date, RFC3339. When provided together with `as_of`, vesting
+    /// is computed from real elapsed time instead of `months_elapsed`.
+    #[serde(default)]
+    pub grant_start: Option<DateTime<Utc>>,
+    /// The date to evaluate vesting as of, RFC3339. Defaults to `months_elapsed`
+    /// based calculation when either this or `grant_start` is absent.
+    #[serde(default)]
+    pub as_of: Option<DateTime<Utc>>,
 }
 
 /// Response returned by the vesting endpoint.
@@ -33,6 +65,14 @@ pub struct VestingResponse {
     pub vested_fraction: f64,
     pub vested_amount: f64,
     pub remaining_amount: f64,
+    /// Exact vested amount in the smallest indivisible unit, computed without
+    /// floating-point rounding. Present only when the request carried `total_units`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub vested_units: Option<u128>,
+    /// Exact remaining amount in the smallest indivisible unit. `vested_units +
+    /// remaining_units` always equals the requested `total_units` exactly.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub remaining_units: Option<u128>,
 }
 
 /// Error type surfaced by the vesting calculations.
@@ -40,8 +80,26 @@ pub struct VestingResponse {
 pub enum VestingError {
     #[error("graded schedule requires total_months greater than cliff_months")]
     InvalidSchedule,
+    #[error("periodic schedule requires period_months greater than zero")]
+    InvalidPeriod,
+    #[error(
+        "schedule spans more than {MAX_PROJECTION_MONTHS} months, which is too large to project"
+    )]
+    ScheduleTooLarge,
+    #[error(
+        "total_units is not supported together with grant_start/as_of timestamps, since the \
+         integer path only has whole-month precision and could disagree with the timestamp-exact \
+         vested_fraction; pass months_elapsed instead"
+    )]
+    TimestampUnitsUnsupported,
 }
 
+/// Upper bound on the month range [`project_schedule`] will materialize into a
+/// `Vec`. Schedules are attacker-controlled `u32` fields on an unauthenticated
+/// endpoint, so without a cap a single request could ask for billions of
+/// `VestingPoint`s. 1200 months (100 years) comfortably covers any real grant.
+const MAX_PROJECTION_MONTHS: u32 = 1_200;
+
 /// Compute the vested fraction for a schedule and elapsed months.
 ///
 /// ```
@@ -57,6 +115,11 @@ pub enum VestingError {
 /// assert_eq!(calculate_vested_fraction(&graded, 6).unwrap(), 0.0);
 /// assert!((calculate_vested_fraction(&graded, 12).unwrap() - 0.25).abs() < f64::EPSILON);
 /// assert_eq!(calculate_vested_fraction(&graded, 30).unwrap(), 1.0);
+///
+/// let periodic = VestingSchedule::Periodic { cliff_months: 6, total_months: 28, period_months: 4 };
+/// assert_eq!(calculate_vested_fraction(&periodic, 6).unwrap(), 0.0);
+/// assert!((calculate_vested_fraction(&periodic, 8).unwrap() - 1.0 / 6.0).abs() < f64::EPSILON);
+/// assert_eq!(calculate_vested_fraction(&periodic, 28).unwrap(), 1.0);
 /// ```
 pub fn calculate_vested_fraction(
     schedule: &VestingSchedule,
@@ -82,37 +145,606 @@ pub fn calculate_vested_fraction(
             }
             let vested_months = months_elapsed.saturating_sub(*cliff_months);
             let denominator = total_months - cliff_months;
-            let fraction = (vested_months as f64 / *denominator as f64).clamp(0.0, 1.0);
+            let fraction = (vested_months as f64 / denominator as f64).clamp(0.0, 1.0);
             Ok(fraction.min(1.0))
         }
+        VestingSchedule::Periodic { .. } => {
+            let (periods_passed, num_periods) = periodic_periods_passed(schedule, months_elapsed)?;
+            Ok(periods_passed as f64 / num_periods as f64)
+        }
+    }
+}
+
+/// Shared step-function math for [`VestingSchedule::Periodic`]: how many whole
+/// periods have closed by `months_elapsed`, and how many periods the window is
+/// divided into in total. Both the fraction and the integer-unit paths derive
+/// from this so they can never disagree with each other.
+fn periodic_periods_passed(
+    schedule: &VestingSchedule,
+    months_elapsed: u32,
+) -> Result<(i64, i64), VestingError> {
+    let (shifted_start, num_periods, period) = periodic_window(schedule)?;
+    let periods_passed = (months_elapsed as i64 - shifted_start).div_euclid(period);
+    Ok((periods_passed.clamp(0, num_periods), num_periods))
+}
+
+/// The even partitioning of a `Periodic` schedule's vesting window:
+/// `(shifted_start, num_periods, period_months)`. `shifted_start` may be
+/// earlier than `cliff_months` (or even negative) so the first period can
+/// absorb a non-evenly-divisible remainder.
+fn periodic_window(schedule: &VestingSchedule) -> Result<(i64, i64, i64), VestingError> {
+    let VestingSchedule::Periodic {
+        cliff_months,
+        total_months,
+        period_months,
+    } = schedule
+    else {
+        unreachable!("periodic_window called with a non-periodic schedule")
+    };
+
+    if *period_months == 0 {
+        return Err(VestingError::InvalidPeriod);
+    }
+    if total_months <= cliff_months {
+        return Err(VestingError::InvalidSchedule);
+    }
+
+    let window = (total_months - cliff_months) as i64;
+    let period = *period_months as i64;
+    let num_periods = (window + period - 1) / period; // ceil(window / period)
+    let remainder = window % period;
+    let shifted_start = if remainder != 0 {
+        *cliff_months as i64 - remainder
+    } else {
+        *cliff_months as i64
+    };
+
+    Ok((shifted_start, num_periods, period))
+}
+
+/// Compute the vested fraction directly from real timestamps instead of a
+/// pre-rounded `months_elapsed` count.
+///
+/// `Graded` schedules get exact sub-month precision: the fraction is the
+/// elapsed time between `vesting_start` (`start_ts + cliff_months`) and
+/// `vesting_end` (`start_ts + total_months`), clamped to `[0, 1]` with
+/// `current_ts <= vesting_start => 0.0` and `current_ts >= vesting_end =>
+/// 1.0`. `Cliff` and `Periodic` schedules step at month boundaries regardless
+/// of precision, so they fall back to the whole-calendar-months count and
+/// [`calculate_vested_fraction`].
+///
+/// ```
+/// use chrono::{Duration, Utc};
+/// use vesting::{calculate_vested_fraction_at, VestingSchedule};
+///
+/// let graded = VestingSchedule::Graded { cliff_months: 6, total_months: 30 };
+/// let start = Utc::now();
+/// assert_eq!(calculate_vested_fraction_at(&graded, start, start).unwrap(), 0.0);
+/// assert_eq!(calculate_vested_fraction_at(&graded, start, start + Duration::days(3650)).unwrap(), 1.0);
+/// ```
+pub fn calculate_vested_fraction_at(
+    schedule: &VestingSchedule,
+    start_ts: DateTime<Utc>,
+    current_ts: DateTime<Utc>,
+) -> Result<f64, VestingError> {
+    match schedule {
+        VestingSchedule::Graded {
+            cliff_months,
+            total_months,
+        } => {
+            if total_months <= cliff_months || *total_months == 0 {
+                return Err(VestingError::InvalidSchedule);
+            }
+            let vesting_start = add_months(start_ts, *cliff_months);
+            let vesting_end = add_months(start_ts, *total_months);
+            if current_ts <= vesting_start {
+                return Ok(0.0);
+            }
+            if current_ts >= vesting_end {
+                return Ok(1.0);
+            }
+            let elapsed = (current_ts - vesting_start)
+                .num_nanoseconds()
+                .unwrap_or(i64::MAX) as f64;
+            let window = (vesting_end - vesting_start).num_nanoseconds().unwrap_or(1) as f64;
+            Ok((elapsed / window).clamp(0.0, 1.0))
+        }
+        VestingSchedule::Cliff { .. } | VestingSchedule::Periodic { .. } => {
+            calculate_vested_fraction(schedule, whole_months_between(start_ts, current_ts))
+        }
+    }
+}
+
+/// Timestamps, when both present, take precedence over `months_elapsed` and
+/// are reduced to the whole calendar months between them; otherwise fall back
+/// to the pre-rounded month count.
+fn effective_months_elapsed(
+    grant_start: Option<DateTime<Utc>>,
+    as_of: Option<DateTime<Utc>>,
+    months_elapsed: u32,
+) -> u32 {
+    match (grant_start, as_of) {
+        (Some(start), Some(current)) => whole_months_between(start, current),
+        _ => months_elapsed,
     }
 }
 
+/// Shift a timestamp forward by a whole number of calendar months.
+fn add_months(ts: DateTime<Utc>, months: u32) -> DateTime<Utc> {
+    ts.checked_add_months(Months::new(months))
+        .unwrap_or(DateTime::<Utc>::MAX_UTC)
+}
+
+/// Whole calendar months elapsed between two timestamps (never negative).
+///
+/// Computed as a closed-form year/month difference adjusted by day-of-month
+/// (rather than walking forward one month at a time), since `start`/`current`
+/// are caller-supplied timestamps on an unauthenticated endpoint and a
+/// year-spanning gap would otherwise mean iterating thousands of months.
+/// `checked_add_months` clamps an overflowing day to the target month's last
+/// day (e.g. Jan 31 + 1 month lands on Feb 28/29), which can make the plain
+/// day/time comparison off by one near month-end, so the result is nudged by
+/// at most one step in either direction to match exactly.
+fn whole_months_between(start: DateTime<Utc>, current: DateTime<Utc>) -> u32 {
+    if current <= start {
+        return 0;
+    }
+    let mut months =
+        (current.year() - start.year()) * 12 + (current.month() as i32 - start.month() as i32);
+    if current.day() < start.day()
+        || (current.day() == start.day() && current.time() < start.time())
+    {
+        months -= 1;
+    }
+    let mut months = months.max(0) as u32;
+    while let Some(next) = start.checked_add_months(Months::new(months + 1)) {
+        if next > current {
+            break;
+        }
+        months += 1;
+    }
+    while months > 0 {
+        match start.checked_add_months(Months::new(months)) {
+            Some(candidate) if candidate <= current => break,
+            _ => months -= 1,
+        }
+    }
+    months
+}
+
+/// Compute the vested amount for a schedule using 128-bit fixed-point math.
+///
+/// Unlike [`calculate_vested_fraction`], this never goes through `f64`: the
+/// unvested amount is derived as `(remaining_periods * total_units) /
+/// num_periods` with 128-bit intermediate precision and rounded *down*, so the
+/// vested amount (`total_units - unvested`) rounds up conservatively and the
+/// two always reconcile exactly to `total_units` with no unit lost or
+/// double-counted.
+///
+/// ```
+/// use vesting::{calculate_vested_amount, VestingSchedule};
+///
+/// let graded = VestingSchedule::Graded { cliff_months: 6, total_months: 30 };
+/// assert_eq!(calculate_vested_amount(&graded, 12, 1_000).unwrap(), 250);
+/// assert_eq!(calculate_vested_amount(&graded, 30, 1_000).unwrap(), 1_000);
+/// ```
+pub fn calculate_vested_amount(
+    schedule: &VestingSchedule,
+    months_elapsed: u32,
+    total_units: u128,
+) -> Result<u128, VestingError> {
+    let unvested = unvested_units(schedule, months_elapsed, total_units)?;
+    Ok(total_units - unvested)
+}
+
+/// Unvested remainder in the smallest indivisible unit, rounded down.
+fn unvested_units(
+    schedule: &VestingSchedule,
+    months_elapsed: u32,
+    total_units: u128,
+) -> Result<u128, VestingError> {
+    match schedule {
+        VestingSchedule::Cliff { cliff_months } => Ok(if months_elapsed >= *cliff_months {
+            0
+        } else {
+            total_units
+        }),
+        VestingSchedule::Graded {
+            cliff_months,
+            total_months,
+        } => {
+            if total_months <= cliff_months || *total_months == 0 {
+                return Err(VestingError::InvalidSchedule);
+            }
+            let effective_elapsed = months_elapsed.clamp(*cliff_months, *total_months);
+            let num_periods = (total_months - cliff_months) as u128;
+            let remaining_periods = (total_months - effective_elapsed) as u128;
+            Ok(proportion_u128(total_units, remaining_periods, num_periods))
+        }
+        VestingSchedule::Periodic { .. } => {
+            let (periods_passed, num_periods) = periodic_periods_passed(schedule, months_elapsed)?;
+            let remaining_periods = (num_periods - periods_passed) as u128;
+            Ok(proportion_u128(
+                total_units,
+                remaining_periods,
+                num_periods as u128,
+            ))
+        }
+    }
+}
+
+/// `floor(total * numerator / denominator)` for `numerator <= denominator`,
+/// computed without the `total * numerator` intermediate overflowing `u128`.
+///
+/// `total` is caller-supplied and can be as large as `u128::MAX`, while
+/// `numerator`/`denominator` here are always small (derived from `u32` month
+/// counts), so splitting `total` into `total / denominator` and `total %
+/// denominator` keeps every intermediate product bounded by `total` itself or
+/// by `denominator * denominator`.
+fn proportion_u128(total: u128, numerator: u128, denominator: u128) -> u128 {
+    let whole = total / denominator;
+    let remainder = total % denominator;
+    whole * numerator + (remainder * numerator) / denominator
+}
+
 async fn calculate_handler(
     Json(request): Json<VestingRequest>,
 ) -> Result<Json<VestingResponse>, (axum::http::StatusCode, Json<serde_json::Value>)> {
-    let fraction = calculate_vested_fraction(&request.schedule, request.months_elapsed)
-        .map_err(|err| {
-            (
-                axum::http::StatusCode::BAD_REQUEST,
-                Json(serde_json::json!({ "error": err.to_string() })),
-            )
-        })?;
+    let bad_request = |err: VestingError| {
+        (
+            axum::http::StatusCode::BAD_REQUEST,
+            Json(serde_json::json!({ "error": err.to_string() })),
+        )
+    };
+
+    let using_timestamps = request.grant_start.is_some() && request.as_of.is_some();
+    if using_timestamps && request.total_units.is_some() {
+        return Err(bad_request(VestingError::TimestampUnitsUnsupported));
+    }
+
+    let fraction = match (request.grant_start, request.as_of) {
+        (Some(start), Some(current)) => {
+            calculate_vested_fraction_at(&request.schedule, start, current).map_err(bad_request)?
+        }
+        _ => calculate_vested_fraction(&request.schedule, request.months_elapsed)
+            .map_err(bad_request)?,
+    };
+    let effective_months_elapsed =
+        effective_months_elapsed(request.grant_start, request.as_of, request.months_elapsed);
 
     let vested_amount = request.total_amount * fraction;
     let remaining_amount = (request.total_amount - vested_amount).max(0.0);
+
+    let (vested_units, remaining_units) = match request.total_units {
+        Some(total_units) => {
+            let vested =
+                calculate_vested_amount(&request.schedule, effective_months_elapsed, total_units)
+                    .map_err(bad_request)?;
+            (Some(vested), Some(total_units - vested))
+        }
+        None => (None, None),
+    };
+
     let response = VestingResponse {
         vested_fraction: fraction,
         vested_amount,
         remaining_amount,
+        vested_units,
+        remaining_units,
     };
 
     Ok(Json(response))
 }
 
-/// Build the Axum router exposing the vesting endpoint.
+/// Input payload for the `/vesting/schedule` projection endpoint.
+#[derive(Debug, Deserialize)]
+pub struct ScheduleRequest {
+    /// Total amount subject to vesting.
+    pub total_amount: f64,
+    /// Schedule configuration.
+    pub schedule: VestingSchedule,
+}
+
+/// A single point on a vesting timeline.
+#[derive(Debug, Serialize, PartialEq)]
+pub struct VestingPoint {
+    /// Months elapsed since the grant start date.
+    pub month: u32,
+    pub vested_fraction: f64,
+    pub vested_amount: f64,
+    pub remaining_amount: f64,
+}
+
+/// Project the full vesting timeline for a schedule, one point per relevant
+/// month: every month for `Cliff`/`Graded` schedules (so the cliff jump and
+/// the final fully-vested point both show up explicitly), or each period
+/// boundary for `Periodic` schedules (since the fraction is flat in between).
+///
+/// ```
+/// use vesting::{project_schedule, VestingSchedule};
+///
+/// let cliff = VestingSchedule::Cliff { cliff_months: 2 };
+/// let points = project_schedule(&cliff, 900.0).unwrap();
+/// assert_eq!(points.len(), 3); // months 0, 1, 2
+/// assert_eq!(points.last().unwrap().vested_amount, 900.0);
+/// ```
+pub fn project_schedule(
+    schedule: &VestingSchedule,
+    total_amount: f64,
+) -> Result<Vec<VestingPoint>, VestingError> {
+    let span_months = match schedule {
+        VestingSchedule::Cliff { cliff_months } => *cliff_months,
+        VestingSchedule::Graded { total_months, .. } => *total_months,
+        VestingSchedule::Periodic { total_months, .. } => *total_months,
+    };
+    if span_months > MAX_PROJECTION_MONTHS {
+        return Err(VestingError::ScheduleTooLarge);
+    }
+
+    let months = match schedule {
+        VestingSchedule::Cliff { cliff_months } => (0..=*cliff_months).collect::<Vec<_>>(),
+        VestingSchedule::Graded { total_months, .. } => (0..=*total_months).collect::<Vec<_>>(),
+        VestingSchedule::Periodic { total_months, .. } => {
+            let (shifted_start, num_periods, period) = periodic_window(schedule)?;
+            let mut months: Vec<u32> = (0..=num_periods)
+                .map(|k| (shifted_start + k * period).max(0) as u32)
+                .filter(|m| *m <= *total_months)
+                .collect();
+            months.push(0);
+            months.push(*total_months);
+            months.sort_unstable();
+            months.dedup();
+            months
+        }
+    };
+
+    months
+        .into_iter()
+        .map(|month| {
+            let vested_fraction = calculate_vested_fraction(schedule, month)?;
+            let vested_amount = total_amount * vested_fraction;
+            let remaining_amount = (total_amount - vested_amount).max(0.0);
+            Ok(VestingPoint {
+                month,
+                vested_fraction,
+                vested_amount,
+                remaining_amount,
+            })
+        })
+        .collect()
+}
+
+async fn schedule_handler(
+    Json(request): Json<ScheduleRequest>,
+) -> Result<Json<Vec<VestingPoint>>, (axum::http::StatusCode, Json<serde_json::Value>)> {
+    let points = project_schedule(&request.schedule, request.total_amount).map_err(|err| {
+        (
+            axum::http::StatusCode::BAD_REQUEST,
+            Json(serde_json::json!({ "error": err.to_string() })),
+        )
+    })?;
+    Ok(Json(points))
+}
+
+/// Input payload for the `/vesting/revoke` endpoint.
+#[derive(Debug, Deserialize)]
+pub struct RevokeRequest {
+    /// Total grant amount in the smallest indivisible unit.
+    pub total_units: u128,
+    /// Months elapsed at the moment the grant is terminated.
+    pub months_elapsed_at_revocation: u32,
+    /// Schedule configuration.
+    pub schedule: VestingSchedule,
+}
+
+/// Result of revoking a grant partway through its schedule.
+#[derive(Debug, Serialize, PartialEq)]
+pub struct RevocationResult {
+    /// Amount the holder keeps: whatever had vested at the revocation point.
+    pub vested_kept: u128,
+    /// Amount clawed back: the remainder, forfeited on termination.
+    pub clawed_back: u128,
+}
+
+/// Freeze vesting at `months_elapsed_at_revocation` and split the grant into
+/// what the holder keeps and what is clawed back. Vesting can never increase
+/// past this point because the result is computed once, at the revocation
+/// month, rather than continuing to track elapsed time. Reuses the
+/// integer-precision path so `vested_kept + clawed_back == total_units`
+/// exactly, with no unit lost or double-counted.
+///
+/// ```
+/// use vesting::{calculate_on_revocation, VestingSchedule};
+///
+/// let graded = VestingSchedule::Graded { cliff_months: 6, total_months: 30 };
+/// let result = calculate_on_revocation(&graded, 12, 1_000).unwrap();
+/// assert_eq!(result.vested_kept, 250);
+/// assert_eq!(result.clawed_back, 750);
+/// ```
+pub fn calculate_on_revocation(
+    schedule: &VestingSchedule,
+    months_elapsed_at_revocation: u32,
+    total_units: u128,
+) -> Result<RevocationResult, VestingError> {
+    let vested_kept = calculate_vested_amount(schedule, months_elapsed_at_revocation, total_units)?;
+    Ok(RevocationResult {
+        vested_kept,
+        clawed_back: total_units - vested_kept,
+    })
+}
+
+async fn revoke_handler(
+    Json(request): Json<RevokeRequest>,
+) -> Result<Json<RevocationResult>, (axum::http::StatusCode, Json<serde_json::Value>)> {
+    let result = calculate_on_revocation(
+        &request.schedule,
+        request.months_elapsed_at_revocation,
+        request.total_units,
+    )
+    .map_err(|err| {
+        (
+            axum::http::StatusCode::BAD_REQUEST,
+            Json(serde_json::json!({ "error": err.to_string() })),
+        )
+    })?;
+    Ok(Json(result))
+}
+
+/// A single grant/tranche within a [`VestingPortfolioRequest`]. Mirrors
+/// [`VestingRequest`]'s fields so the same amount, `total_units` and
+/// timestamp conventions apply per-grant.
+#[derive(Debug, Deserialize)]
+pub struct GrantEntry {
+    /// Total amount of this grant.
+    pub total_amount: f64,
+    /// Months elapsed since this grant's own start date.
+    pub months_elapsed: u32,
+    /// Schedule configuration for this grant.
+    pub schedule: VestingSchedule,
+    /// Total amount expressed in the smallest indivisible unit (cents, wei, ...).
+    /// See [`VestingRequest::total_units`] for the same caveat around
+    /// combining this with `grant_start`/`as_of`.
+    #[serde(default)]
+    pub total_units: Option<u128>,
+    /// Grant start date, RFC3339. When provided together with `as_of`, vesting
+    /// is computed from real elapsed time instead of `months_elapsed`.
+    #[serde(default)]
+    pub grant_start: Option<DateTime<Utc>>,
+    /// The date to evaluate vesting as of, RFC3339.
+    #[serde(default)]
+    pub as_of: Option<DateTime<Utc>>,
+}
+
+/// Input payload for the `/vesting/portfolio` endpoint.
+#[derive(Debug, Deserialize)]
+pub struct VestingPortfolioRequest {
+    pub grants: Vec<GrantEntry>,
+}
+
+/// Per-grant result within a [`PortfolioResponse`].
+#[derive(Debug, Serialize, PartialEq)]
+pub struct GrantResult {
+    pub vested_fraction: f64,
+    pub vested_amount: f64,
+    pub remaining_amount: f64,
+    pub total_amount: f64,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub vested_units: Option<u128>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub remaining_units: Option<u128>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub total_units: Option<u128>,
+}
+
+/// Response returned by the `/vesting/portfolio` endpoint: one result per
+/// grant plus the rolled-up total across the whole portfolio.
+#[derive(Debug, Serialize, PartialEq)]
+pub struct PortfolioResponse {
+    pub grants: Vec<GrantResult>,
+    pub total_vested_amount: f64,
+    pub total_remaining_amount: f64,
+    pub total_grant_amount: f64,
+    /// Sum of `vested_units` across grants. Present only when every grant in
+    /// the portfolio supplied `total_units`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub total_vested_units: Option<u128>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub total_remaining_units: Option<u128>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub total_grant_units: Option<u128>,
+}
+
+/// Compute per-grant vesting plus the rolled-up portfolio total, so a caller
+/// holding several overlapping grants (hire grant, refreshers, bonuses)
+/// doesn't have to fan out N requests and sum them client-side.
+pub fn calculate_portfolio(
+    request: &VestingPortfolioRequest,
+) -> Result<PortfolioResponse, VestingError> {
+    let mut grants = Vec::with_capacity(request.grants.len());
+    let (mut total_vested_amount, mut total_remaining_amount, mut total_grant_amount) =
+        (0.0, 0.0, 0.0);
+    let (mut total_vested_units, mut total_remaining_units, mut total_grant_units) =
+        (Some(0u128), Some(0u128), Some(0u128));
+
+    for grant in &request.grants {
+        let using_timestamps = grant.grant_start.is_some() && grant.as_of.is_some();
+        if using_timestamps && grant.total_units.is_some() {
+            return Err(VestingError::TimestampUnitsUnsupported);
+        }
+
+        let fraction = match (grant.grant_start, grant.as_of) {
+            (Some(start), Some(current)) => {
+                calculate_vested_fraction_at(&grant.schedule, start, current)?
+            }
+            _ => calculate_vested_fraction(&grant.schedule, grant.months_elapsed)?,
+        };
+        let vested_amount = grant.total_amount * fraction;
+        let remaining_amount = (grant.total_amount - vested_amount).max(0.0);
+
+        total_vested_amount += vested_amount;
+        total_remaining_amount += remaining_amount;
+        total_grant_amount += grant.total_amount;
+
+        let (vested_units, remaining_units) = match grant.total_units {
+            Some(total_units) => {
+                let months_elapsed =
+                    effective_months_elapsed(grant.grant_start, grant.as_of, grant.months_elapsed);
+                let vested = calculate_vested_amount(&grant.schedule, months_elapsed, total_units)?;
+                let remaining = total_units - vested;
+                total_vested_units = total_vested_units.map(|t| t + vested);
+                total_remaining_units = total_remaining_units.map(|t| t + remaining);
+                total_grant_units = total_grant_units.map(|t| t + total_units);
+                (Some(vested), Some(remaining))
+            }
+            None => {
+                total_vested_units = None;
+                total_remaining_units = None;
+                total_grant_units = None;
+                (None, None)
+            }
+        };
+
+        grants.push(GrantResult {
+            vested_fraction: fraction,
+            vested_amount,
+            remaining_amount,
+            total_amount: grant.total_amount,
+            vested_units,
+            remaining_units,
+            total_units: grant.total_units,
+        });
+    }
+
+    Ok(PortfolioResponse {
+        grants,
+        total_vested_amount,
+        total_remaining_amount,
+        total_grant_amount,
+        total_vested_units,
+        total_remaining_units,
+        total_grant_units,
+    })
+}
+
+async fn portfolio_handler(
+    Json(request): Json<VestingPortfolioRequest>,
+) -> Result<Json<PortfolioResponse>, (axum::http::StatusCode, Json<serde_json::Value>)> {
+    let response = calculate_portfolio(&request).map_err(|err| {
+        (
+            axum::http::StatusCode::BAD_REQUEST,
+            Json(serde_json::json!({ "error": err.to_string() })),
+        )
+    })?;
+    Ok(Json(response))
+}
+
+/// Build the Axum router exposing the vesting endpoints.
 pub fn router() -> Router {
-    Router::new().route("/vesting/calculate", post(calculate_handler))
+    Router::new()
+        .route("/vesting/calculate", post(calculate_handler))
+        .route("/vesting/schedule", post(schedule_handler))
+        .route("/vesting/revoke", post(revoke_handler))
+        .route("/vesting/portfolio", post(portfolio_handler))
 }
 
 /// Start serving the vesting API on the provided address.
@@ -147,11 +779,479 @@ mod tests {
                 cliff_months: 6,
                 total_months: 24,
             },
+            total_units: None,
+            grant_start: None,
+            as_of: None,
+        };
+
+        let Json(response) = calculate_handler(Json(request)).await.unwrap();
+        assert!((response.vested_fraction - 1.0 / 6.0).abs() < f64::EPSILON);
+        assert!((response.vested_amount - 1000.0 / 6.0).abs() < f64::EPSILON);
+        assert!((response.remaining_amount - 5000.0 / 6.0).abs() < f64::EPSILON);
+        assert_eq!(response.vested_units, None);
+        assert_eq!(response.remaining_units, None);
+    }
+
+    #[tokio::test]
+    async fn handler_reports_exact_units_when_requested() {
+        let request = VestingRequest {
+            total_amount: 1000.0,
+            months_elapsed: 9,
+            schedule: VestingSchedule::Graded {
+                cliff_months: 6,
+                total_months: 24,
+            },
+            total_units: Some(1_000_000),
+            grant_start: None,
+            as_of: None,
         };
 
         let Json(response) = calculate_handler(Json(request)).await.unwrap();
-        assert!((response.vested_fraction - 0.125).abs() < f64::EPSILON);
-        assert!((response.vested_amount - 125.0).abs() < f64::EPSILON);
-        assert!((response.remaining_amount - 875.0).abs() < f64::EPSILON);
+        assert_eq!(response.vested_units, Some(166_667));
+        assert_eq!(response.remaining_units, Some(833_333));
+    }
+
+    #[test]
+    fn integer_path_reconciles_exactly_for_indivisible_fractions() {
+        let schedule = VestingSchedule::Graded {
+            cliff_months: 0,
+            total_months: 3,
+        };
+        for total_units in [1_u128, 2, 7, 100, 1_000_000_000] {
+            for months_elapsed in 0..=3 {
+                let vested =
+                    calculate_vested_amount(&schedule, months_elapsed, total_units).unwrap();
+                assert!(vested <= total_units);
+            }
+        }
+        // One unit split across three periods: the unvested remainder floors
+        // (2/3 and 1/3 both round down), so the leftover unit vests as soon
+        // as the first period closes rather than waiting on the last one.
+        assert_eq!(calculate_vested_amount(&schedule, 0, 1).unwrap(), 0);
+        assert_eq!(calculate_vested_amount(&schedule, 1, 1).unwrap(), 1);
+        assert_eq!(calculate_vested_amount(&schedule, 2, 1).unwrap(), 1);
+        assert_eq!(calculate_vested_amount(&schedule, 3, 1).unwrap(), 1);
+    }
+
+    #[test]
+    fn integer_path_does_not_overflow_near_u128_max() {
+        let graded = VestingSchedule::Graded {
+            cliff_months: 6,
+            total_months: 30,
+        };
+        let total_units = u128::MAX - 1;
+        let vested = calculate_vested_amount(&graded, 12, total_units).unwrap();
+        let remaining = total_units - vested;
+        assert_eq!(vested + remaining, total_units);
+        // Roughly 1/4 of the way through the graded window.
+        assert!(vested > total_units / 5 && vested < total_units / 3);
+
+        let periodic = VestingSchedule::Periodic {
+            cliff_months: 6,
+            total_months: 28,
+            period_months: 4,
+        };
+        let vested = calculate_vested_amount(&periodic, 12, total_units).unwrap();
+        let remaining = total_units - vested;
+        assert_eq!(vested + remaining, total_units);
+    }
+
+    #[test]
+    fn integer_path_rejects_invalid_graded_schedule() {
+        let schedule = VestingSchedule::Graded {
+            cliff_months: 12,
+            total_months: 12,
+        };
+        assert_eq!(
+            calculate_vested_amount(&schedule, 12, 1_000).unwrap_err(),
+            VestingError::InvalidSchedule
+        );
+    }
+
+    #[test]
+    fn periodic_schedule_steps_at_boundaries_with_shifted_start() {
+        // window = 28 - 6 = 22, not evenly divisible by 4, so the first
+        // period is shortened (6 -> 8) and the remaining five periods are
+        // full 4-month steps, landing exactly on total_months.
+        let schedule = VestingSchedule::Periodic {
+            cliff_months: 6,
+            total_months: 28,
+            period_months: 4,
+        };
+        assert_eq!(calculate_vested_fraction(&schedule, 0).unwrap(), 0.0);
+        assert_eq!(calculate_vested_fraction(&schedule, 7).unwrap(), 0.0);
+        assert!(
+            (calculate_vested_fraction(&schedule, 8).unwrap() - 1.0 / 6.0).abs() < f64::EPSILON
+        );
+        assert!(
+            (calculate_vested_fraction(&schedule, 11).unwrap() - 1.0 / 6.0).abs() < f64::EPSILON
+        );
+        assert!(
+            (calculate_vested_fraction(&schedule, 12).unwrap() - 2.0 / 6.0).abs() < f64::EPSILON
+        );
+        assert_eq!(calculate_vested_fraction(&schedule, 28).unwrap(), 1.0);
+        assert_eq!(calculate_vested_fraction(&schedule, 40).unwrap(), 1.0);
+    }
+
+    #[test]
+    fn periodic_schedule_rejects_zero_period() {
+        let schedule = VestingSchedule::Periodic {
+            cliff_months: 6,
+            total_months: 28,
+            period_months: 0,
+        };
+        assert_eq!(
+            calculate_vested_fraction(&schedule, 10).unwrap_err(),
+            VestingError::InvalidPeriod
+        );
+    }
+
+    #[test]
+    fn periodic_schedule_integer_path_matches_fraction_path() {
+        let schedule = VestingSchedule::Periodic {
+            cliff_months: 6,
+            total_months: 28,
+            period_months: 4,
+        };
+        let total_units = 600_000_u128;
+        assert_eq!(
+            calculate_vested_amount(&schedule, 12, total_units).unwrap(),
+            200_000
+        );
+        assert_eq!(
+            calculate_vested_amount(&schedule, 28, total_units).unwrap(),
+            total_units
+        );
+    }
+
+    #[test]
+    fn timestamp_based_graded_vesting_is_sub_month_exact() {
+        let schedule = VestingSchedule::Graded {
+            cliff_months: 6,
+            total_months: 30,
+        };
+        let start = DateTime::parse_from_rfc3339("2024-01-01T00:00:00Z")
+            .unwrap()
+            .with_timezone(&Utc);
+        let vesting_start = add_months(start, 6);
+        let vesting_end = add_months(start, 30);
+        let midpoint = vesting_start + (vesting_end - vesting_start) / 2;
+
+        assert_eq!(
+            calculate_vested_fraction_at(&schedule, start, vesting_start).unwrap(),
+            0.0
+        );
+        assert_eq!(
+            calculate_vested_fraction_at(&schedule, start, vesting_end).unwrap(),
+            1.0
+        );
+        assert!(
+            (calculate_vested_fraction_at(&schedule, start, midpoint).unwrap() - 0.5).abs() < 1e-9
+        );
+    }
+
+    #[test]
+    fn timestamp_based_cliff_vesting_falls_back_to_whole_months() {
+        let schedule = VestingSchedule::Cliff { cliff_months: 12 };
+        let start = DateTime::parse_from_rfc3339("2024-01-01T00:00:00Z")
+            .unwrap()
+            .with_timezone(&Utc);
+        let before_cliff = add_months(start, 11) + chrono::Duration::days(20);
+        let after_cliff = add_months(start, 12);
+
+        assert_eq!(
+            calculate_vested_fraction_at(&schedule, start, before_cliff).unwrap(),
+            0.0
+        );
+        assert_eq!(
+            calculate_vested_fraction_at(&schedule, start, after_cliff).unwrap(),
+            1.0
+        );
+    }
+
+    #[tokio::test]
+    async fn handler_prefers_timestamps_over_months_elapsed_when_both_present() {
+        let start = DateTime::parse_from_rfc3339("2024-01-01T00:00:00Z")
+            .unwrap()
+            .with_timezone(&Utc);
+        let schedule = VestingSchedule::Graded {
+            cliff_months: 6,
+            total_months: 30,
+        };
+        let vesting_start = add_months(start, 6);
+        let vesting_end = add_months(start, 30);
+        let midpoint = vesting_start + (vesting_end - vesting_start) / 2;
+
+        let request = VestingRequest {
+            total_amount: 1000.0,
+            months_elapsed: 0, // deliberately stale; timestamps should win
+            schedule,
+            total_units: None,
+            grant_start: Some(start),
+            as_of: Some(midpoint),
+        };
+
+        let Json(response) = calculate_handler(Json(request)).await.unwrap();
+        assert!((response.vested_fraction - 0.5).abs() < 1e-9);
+    }
+
+    #[tokio::test]
+    async fn handler_rejects_total_units_combined_with_timestamps() {
+        let start = DateTime::parse_from_rfc3339("2024-01-01T00:00:00Z")
+            .unwrap()
+            .with_timezone(&Utc);
+        let request = VestingRequest {
+            total_amount: 1000.0,
+            months_elapsed: 0,
+            schedule: VestingSchedule::Graded {
+                cliff_months: 6,
+                total_months: 30,
+            },
+            total_units: Some(1_000_000),
+            grant_start: Some(start),
+            as_of: Some(add_months(start, 12)),
+        };
+
+        let err = calculate_handler(Json(request)).await.unwrap_err();
+        assert_eq!(err.0, axum::http::StatusCode::BAD_REQUEST);
+    }
+
+    #[test]
+    fn projects_graded_schedule_month_by_month() {
+        let schedule = VestingSchedule::Graded {
+            cliff_months: 6,
+            total_months: 30,
+        };
+        let points = project_schedule(&schedule, 2_400.0).unwrap();
+        assert_eq!(points.len(), 31);
+        assert_eq!(points[0].vested_amount, 0.0);
+        assert_eq!(points[6].vested_amount, 0.0);
+        assert!((points[12].vested_fraction - 0.25).abs() < f64::EPSILON);
+        assert_eq!(points[30].vested_amount, 2_400.0);
+        assert_eq!(points[30].remaining_amount, 0.0);
+    }
+
+    #[test]
+    fn projects_periodic_schedule_at_boundaries_only() {
+        let schedule = VestingSchedule::Periodic {
+            cliff_months: 6,
+            total_months: 28,
+            period_months: 4,
+        };
+        let points = project_schedule(&schedule, 600_000.0).unwrap();
+        let months: Vec<u32> = points.iter().map(|p| p.month).collect();
+        assert_eq!(months, vec![0, 4, 8, 12, 16, 20, 24, 28]);
+        assert_eq!(points.last().unwrap().vested_amount, 600_000.0);
+    }
+
+    #[tokio::test]
+    async fn schedule_handler_returns_full_timeline() {
+        let request = ScheduleRequest {
+            total_amount: 1_000.0,
+            schedule: VestingSchedule::Cliff { cliff_months: 3 },
+        };
+        let Json(points) = schedule_handler(Json(request)).await.unwrap();
+        assert_eq!(points.len(), 4);
+        assert_eq!(points[2].vested_amount, 0.0);
+        assert_eq!(points[3].vested_amount, 1_000.0);
+    }
+
+    #[test]
+    fn project_schedule_rejects_oversized_cliff_without_allocating() {
+        let schedule = VestingSchedule::Cliff {
+            cliff_months: 4_000_000_000,
+        };
+        assert_eq!(
+            project_schedule(&schedule, 1.0).unwrap_err(),
+            VestingError::ScheduleTooLarge
+        );
+    }
+
+    #[test]
+    fn project_schedule_rejects_oversized_graded_and_periodic() {
+        let graded = VestingSchedule::Graded {
+            cliff_months: 6,
+            total_months: 4_000_000_000,
+        };
+        assert_eq!(
+            project_schedule(&graded, 1.0).unwrap_err(),
+            VestingError::ScheduleTooLarge
+        );
+
+        let periodic = VestingSchedule::Periodic {
+            cliff_months: 6,
+            total_months: 4_000_000_000,
+            period_months: 1,
+        };
+        assert_eq!(
+            project_schedule(&periodic, 1.0).unwrap_err(),
+            VestingError::ScheduleTooLarge
+        );
+    }
+
+    #[tokio::test]
+    async fn schedule_handler_rejects_oversized_schedule() {
+        let request = ScheduleRequest {
+            total_amount: 1.0,
+            schedule: VestingSchedule::Cliff {
+                cliff_months: 4_000_000_000,
+            },
+        };
+        let err = schedule_handler(Json(request)).await.unwrap_err();
+        assert_eq!(err.0, axum::http::StatusCode::BAD_REQUEST);
+    }
+
+    #[test]
+    fn revocation_splits_grant_exactly() {
+        let schedule = VestingSchedule::Graded {
+            cliff_months: 6,
+            total_months: 30,
+        };
+        let result = calculate_on_revocation(&schedule, 12, 1_000).unwrap();
+        assert_eq!(result.vested_kept, 250);
+        assert_eq!(result.clawed_back, 750);
+        assert_eq!(result.vested_kept + result.clawed_back, 1_000);
+    }
+
+    #[test]
+    fn revocation_before_cliff_claws_back_everything() {
+        let schedule = VestingSchedule::Cliff { cliff_months: 12 };
+        let result = calculate_on_revocation(&schedule, 6, 1_000).unwrap();
+        assert_eq!(result.vested_kept, 0);
+        assert_eq!(result.clawed_back, 1_000);
+    }
+
+    #[test]
+    fn revocation_after_full_vest_keeps_everything() {
+        let schedule = VestingSchedule::Cliff { cliff_months: 12 };
+        let result = calculate_on_revocation(&schedule, 24, 1_000).unwrap();
+        assert_eq!(result.vested_kept, 1_000);
+        assert_eq!(result.clawed_back, 0);
+    }
+
+    #[tokio::test]
+    async fn revoke_handler_reconciles_to_total_units() {
+        let request = RevokeRequest {
+            total_units: 777,
+            months_elapsed_at_revocation: 18,
+            schedule: VestingSchedule::Periodic {
+                cliff_months: 6,
+                total_months: 28,
+                period_months: 4,
+            },
+        };
+        let Json(result) = revoke_handler(Json(request)).await.unwrap();
+        assert_eq!(result.vested_kept + result.clawed_back, 777);
+    }
+
+    #[test]
+    fn portfolio_rolls_up_multiple_grants() {
+        let request = VestingPortfolioRequest {
+            grants: vec![
+                GrantEntry {
+                    total_amount: 1_000.0,
+                    total_units: Some(1_000),
+                    months_elapsed: 30,
+                    schedule: VestingSchedule::Cliff { cliff_months: 12 },
+                    grant_start: None,
+                    as_of: None,
+                },
+                GrantEntry {
+                    total_amount: 1_000.0,
+                    total_units: Some(1_000),
+                    months_elapsed: 12,
+                    schedule: VestingSchedule::Graded {
+                        cliff_months: 6,
+                        total_months: 30,
+                    },
+                    grant_start: None,
+                    as_of: None,
+                },
+            ],
+        };
+
+        let response = calculate_portfolio(&request).unwrap();
+        assert_eq!(response.grants.len(), 2);
+        assert_eq!(response.grants[0].vested_amount, 1_000.0);
+        assert_eq!(response.grants[1].vested_amount, 250.0);
+        assert_eq!(response.grants[0].vested_units, Some(1_000));
+        assert_eq!(response.grants[1].vested_units, Some(250));
+        assert_eq!(response.total_vested_amount, 1_250.0);
+        assert_eq!(response.total_remaining_amount, 750.0);
+        assert_eq!(response.total_grant_amount, 2_000.0);
+        assert_eq!(response.total_vested_units, Some(1_250));
+        assert_eq!(response.total_remaining_units, Some(750));
+        assert_eq!(response.total_grant_units, Some(2_000));
+    }
+
+    #[test]
+    fn portfolio_omits_unit_totals_when_any_grant_lacks_total_units() {
+        let request = VestingPortfolioRequest {
+            grants: vec![
+                GrantEntry {
+                    total_amount: 1_000.0,
+                    total_units: Some(1_000),
+                    months_elapsed: 12,
+                    schedule: VestingSchedule::Cliff { cliff_months: 12 },
+                    grant_start: None,
+                    as_of: None,
+                },
+                GrantEntry {
+                    total_amount: 500.0,
+                    total_units: None,
+                    months_elapsed: 12,
+                    schedule: VestingSchedule::Cliff { cliff_months: 12 },
+                    grant_start: None,
+                    as_of: None,
+                },
+            ],
+        };
+
+        let response = calculate_portfolio(&request).unwrap();
+        assert_eq!(response.total_vested_amount, 1_500.0);
+        assert_eq!(response.total_vested_units, None);
+        assert_eq!(response.total_remaining_units, None);
+        assert_eq!(response.total_grant_units, None);
+    }
+
+    #[test]
+    fn portfolio_rejects_total_units_combined_with_timestamps() {
+        let start = DateTime::parse_from_rfc3339("2024-01-01T00:00:00Z")
+            .unwrap()
+            .with_timezone(&Utc);
+        let request = VestingPortfolioRequest {
+            grants: vec![GrantEntry {
+                total_amount: 1_000.0,
+                total_units: Some(1_000),
+                months_elapsed: 0,
+                schedule: VestingSchedule::Cliff { cliff_months: 12 },
+                grant_start: Some(start),
+                as_of: Some(add_months(start, 6)),
+            }],
+        };
+
+        assert_eq!(
+            calculate_portfolio(&request).unwrap_err(),
+            VestingError::TimestampUnitsUnsupported
+        );
+    }
+
+    #[tokio::test]
+    async fn portfolio_handler_returns_rolled_up_response() {
+        let request = VestingPortfolioRequest {
+            grants: vec![GrantEntry {
+                total_amount: 500.0,
+                total_units: Some(500),
+                months_elapsed: 6,
+                schedule: VestingSchedule::Cliff { cliff_months: 6 },
+                grant_start: None,
+                as_of: None,
+            }],
+        };
+
+        let Json(response) = portfolio_handler(Json(request)).await.unwrap();
+        assert_eq!(response.total_vested_amount, 500.0);
+        assert_eq!(response.total_remaining_amount, 0.0);
+        assert_eq!(response.total_vested_units, Some(500));
     }
 }